@@ -0,0 +1,152 @@
+//! Proc-macro companion crate for `airtable`.
+//!
+//! Provides `#[derive(Airtable)]`, which generates the `airtable::Record` impl
+//! (`set_id`/`id`) so callers don't have to hand-write it for every struct.
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident};
+
+/// Derives `airtable::Record` for a struct.
+///
+/// Looks for a field named `id` of type `String`, or a field annotated with
+/// `#[airtable(id)]` if the id field is named something else. The field's
+/// `#[serde(rename)]`/`#[serde(default, skip_serializing)]` attributes (if
+/// any) are left untouched; this macro only needs to know which field to
+/// read from and write to.
+#[proc_macro_derive(Airtable, attributes(airtable))]
+pub fn derive_airtable(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let id_field = match find_id_field(&input) {
+        Ok(field) => field,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let expanded = quote! {
+        impl airtable::Record for #name {
+            fn set_id(&mut self, id: String) {
+                self.#id_field = id;
+            }
+
+            fn id(&self) -> &str {
+                &self.#id_field
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+fn find_id_field(input: &DeriveInput) -> syn::Result<Ident> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "derive(Airtable) only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "derive(Airtable) can only be applied to structs",
+            ))
+        }
+    };
+
+    // A field explicitly marked `#[airtable(id)]` always wins.
+    for field in fields {
+        if field.attrs.iter().any(is_airtable_id_attr) {
+            return Ok(field.ident.clone().unwrap());
+        }
+    }
+
+    // Otherwise fall back to a field literally named `id` of type `String`.
+    for field in fields {
+        if field.ident.as_ref().map(|i| i == "id").unwrap_or(false) && is_string_type(&field.ty) {
+            return Ok(field.ident.clone().unwrap());
+        }
+    }
+
+    Err(syn::Error::new_spanned(
+        &input.ident,
+        "derive(Airtable) needs a `String` field named `id`, or a field annotated with `#[airtable(id)]`",
+    ))
+}
+
+fn is_airtable_id_attr(attr: &syn::Attribute) -> bool {
+    if !attr.path.is_ident("airtable") {
+        return false;
+    }
+
+    attr.parse_args::<Ident>()
+        .map(|ident| ident == "id")
+        .unwrap_or(false)
+}
+
+fn is_string_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        return type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "String")
+            .unwrap_or(false);
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_id_field;
+    use syn::DeriveInput;
+
+    fn parse(source: &str) -> DeriveInput {
+        syn::parse_str(source).expect("fixture should parse as a DeriveInput")
+    }
+
+    #[test]
+    fn finds_a_field_literally_named_id() {
+        let input = parse("struct Word { id: String, word: String }");
+        assert_eq!(find_id_field(&input).unwrap(), "id");
+    }
+
+    #[test]
+    fn airtable_id_attribute_overrides_the_id_field_name() {
+        let input = parse("struct Word { #[airtable(id)] record_id: String, id: i64 }");
+        assert_eq!(find_id_field(&input).unwrap(), "record_id");
+    }
+
+    #[test]
+    fn errors_when_there_is_no_id_field() {
+        let input = parse("struct Word { word: String }");
+        assert!(find_id_field(&input).is_err());
+    }
+
+    #[test]
+    fn errors_when_the_id_field_is_not_a_string() {
+        let input = parse("struct Word { id: i64 }");
+        assert!(find_id_field(&input).is_err());
+    }
+
+    #[test]
+    fn errors_on_a_tuple_struct() {
+        let input = parse("struct Word(String);");
+        assert!(find_id_field(&input).is_err());
+    }
+
+    #[test]
+    fn errors_on_an_enum() {
+        let input = parse("enum Word { A, B }");
+        assert!(find_id_field(&input).is_err());
+    }
+}
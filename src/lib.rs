@@ -13,15 +13,19 @@
 //! Add `airtable = "*"` to your `Cargo.toml`.
 //! 
 //! ### Example
-//! 
-//! ```
+//!
+//! ```no_run
 //! extern crate dotenv;
 //! extern crate serde;
-//! 
+//!
 //! use dotenv::dotenv;
 //! use std::env;
 //! use serde::{Serialize, Deserialize};
+//! use airtable::Airtable;
+//! use futures::StreamExt;
 //!
+//! # #[tokio::main]
+//! # async fn main() {
 //! // You don't need to use dotenv. I use it here because it makes it much easier to test without
 //! // publishing my keys to the kingdom :-)
 //! dotenv().ok();
@@ -32,7 +36,7 @@
 //! //
 //! // In this case, I'm mapping words that I have highlighted on my kindle with the # of results
 //! // on Google so I can choose which ones to learn first.
-//! #[derive(Serialize, Deserialize, Debug, Default)]
+//! #[derive(Serialize, Deserialize, Airtable, Debug, Default)]
 //! struct Word {
 //!     #[serde(default, skip_serializing)]
 //!     id: String,
@@ -44,20 +48,9 @@
 //!     next: bool,
 //! }
 //!
-//! // We need to define two methods on the structure so that ids can be assigned to it.
-//! //
-//! // TODO: Convert this to be a `derive(Airtable)` and be automatically defined but panic if the
-//! // `id` is not a member of the struct and is a String. Contributions welcome for this or
-//! // another ergonomic solution.
-//! impl airtable::Record for Word {
-//!     fn set_id(&mut self, id: String) {
-//!         self.id = id;
-//!     }
-//! 
-//!     fn id(&self) -> &str {
-//!         &self.id
-//!     }
-//! }
+//! // `derive(Airtable)` finds the `id: String` field above and generates the
+//! // `airtable::Record` impl (`set_id`/`id`) for us. If your id field is named
+//! // something else, mark it with `#[airtable(id)]` instead.
 //!
 //! // Define the base object to operate on.
 //! let base = airtable::new::<Word>(
@@ -66,8 +59,8 @@
 //!     "Words",
 //! );
 //!
-//! // Query on the base. This implements the Iterator Trait and will paginate when reaching a page
-//! // boundary. If you remove the `take(200)`, it'll just paginate through everything.
+//! // Query on the base. This returns a `Stream` that paginates lazily as you poll it.
+//! // If you remove the `take(200)`, it'll just paginate through everything.
 //! let mut results: Vec<_> = base
 //!     .query()
 //!     .view("To Learn")
@@ -75,9 +68,12 @@
 //!     .sort("Google", airtable::SortDirection::Descending)
 //!     .sort("Created", airtable::SortDirection::Descending)
 //!     .formula("FIND(\"Harry Potter\", Source)")
-//!     .into_iter()
+//!     .fields(&["Word", "Google"])
+//!     .into_stream()
 //!     .take(200)
-//!     .collect();
+//!     .map(|word| word.unwrap())
+//!     .collect()
+//!     .await;
 //!
 //! // Pop the first element by taking ownership of it and print it
 //! let mut word = results.remove(0);
@@ -85,7 +81,7 @@
 //!
 //! // Toggle the flag and update the record.
 //! word.next = !word.next;
-//! base.update(&word);
+//! base.update(&word).await.unwrap();
 //!
 //! // Create a new word!
 //! let mut new_word = Word {
@@ -96,38 +92,172 @@
 //!     .. Default::default()
 //! };
 //!
-//! println!("{:?}", base.create(&new_word));
+//! println!("{:?}", base.create(&new_word).await);
+//! # }
 //! ```
-//! 
+//!
 //! License: MIT
 #![allow(dead_code)]
-extern crate failure;
 extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
+extern crate serde_urlencoded;
+extern crate airtable_derive;
+extern crate futures;
+extern crate rand;
+extern crate tokio;
 
 #[cfg(test)]
 extern crate mockito;
 
+pub use airtable_derive::Airtable;
+
 use serde::{Serialize, Deserialize};
-use failure::Error;
+use futures::future::BoxFuture;
+use futures::stream::Stream;
+use rand::Rng;
 use reqwest::header;
 use reqwest::Url;
+use std::fmt;
 use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::sync::Arc;
+use std::time::Duration;
 
-const URL: &str = "https://api.airtable.com/v0";
+/// Callback invoked on every outgoing request just before it's sent, so
+/// callers can add headers, log, refresh auth, or throttle without forking
+/// the crate.
+type RequestHook = dyn Fn(&mut reqwest::RequestBuilder) + Send + Sync;
 
+/// Errors returned by this crate.
+///
+/// `Http` and `Deserialization` carry the parsed response body (when Airtable
+/// sent one and it was valid JSON) alongside the underlying error, so callers
+/// can see *why* a request failed -- e.g. Airtable's
+/// `{"error":{"type":"INVALID_VALUE_FOR_COLUMN","message":"..."}}` payload --
+/// instead of just a bare status code.
 #[derive(Debug)]
+pub enum Error {
+    /// A non-2xx response, or a transport-level failure from reqwest.
+    Http(reqwest::Error, Option<serde_json::Value>),
+    /// The response body couldn't be deserialized into the expected type.
+    Deserialization(serde_json::Error, Option<serde_json::Value>),
+    /// A record couldn't be serialized into a request body.
+    Serialization(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(err, Some(body)) => write!(f, "{} (response body: {})", err, body),
+            Error::Http(err, None) => write!(f, "{}", err),
+            Error::Deserialization(err, Some(body)) => {
+                write!(f, "failed to deserialize response: {} (body: {})", err, body)
+            }
+            Error::Deserialization(err, None) => write!(f, "failed to deserialize response: {}", err),
+            Error::Serialization(err) => write!(f, "failed to serialize request body: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http(err, _) => Some(err),
+            Error::Deserialization(err, _) => Some(err),
+            Error::Serialization(err) => Some(err),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err, None)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Serialization(err)
+    }
+}
+
+/// Checks `response`'s status, reading the body into the error when it's not
+/// a success so callers can see what Airtable rejected.
+async fn response_or_error(response: reqwest::Response) -> Result<reqwest::Response, Error> {
+    if let Err(err) = response.error_for_status_ref() {
+        let body = response
+            .text()
+            .await
+            .ok()
+            .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok());
+
+        return Err(Error::Http(err, body));
+    }
+
+    Ok(response)
+}
+
+/// Reads `response`'s body and deserializes it as `T`, attaching the raw body
+/// to the error if it parses as JSON but not as `T`.
+async fn read_json<T>(response: reqwest::Response) -> Result<T, Error>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let text = response.text().await?;
+
+    serde_json::from_str(&text).map_err(|err| {
+        let raw = serde_json::from_str::<serde_json::Value>(&text).ok();
+        Error::Deserialization(err, raw)
+    })
+}
+
+const URL: &str = "https://api.airtable.com/v0";
+
+// Defaults for the retry layer. Without a `Retry-After` header, five retries
+// starting at 200ms sleep 6.2s in total before jitter (at most 12.4s with it),
+// which is shorter than the 30s lockout Airtable applies to a base on a 429.
+const DEFAULT_MAX_RETRIES: u32 = 5;
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(200);
+
+// `max_retries` is caller-controlled and unbounded, so the backoff itself
+// needs a ceiling: past this many attempts the exponent stops growing (so
+// `2u32.pow(attempt)` can't overflow), and the delay never exceeds `MAX_BACKOFF`.
+const MAX_BACKOFF_ATTEMPTS: u32 = 10;
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
 pub struct Base<T: Record> {
     http_client: reqwest::Client,
 
+    base_url: String,
     table: String,
     api_key: String,
     app_key: String,
 
+    max_retries: u32,
+    base_delay: Duration,
+    request_hook: Option<Arc<RequestHook>>,
+
     phantom: PhantomData<T>,
 }
 
+impl<T: Record> fmt::Debug for Base<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Base")
+            .field("http_client", &self.http_client)
+            .field("base_url", &self.base_url)
+            .field("table", &self.table)
+            .field("api_key", &self.api_key)
+            .field("app_key", &self.app_key)
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
+            .field("request_hook", &self.request_hook.is_some())
+            .field("phantom", &self.phantom)
+            .finish()
+    }
+}
+
 pub fn new<T>(api_key: &str, app_key: &str, table: &str) -> Base<T>
 where
     T: Record,
@@ -150,13 +280,102 @@ where
 
     Base {
         http_client,
+        base_url: URL.to_owned(),
         api_key: api_key.to_owned(),
         app_key: app_key.to_owned(),
         table: table.to_owned(),
+        max_retries: DEFAULT_MAX_RETRIES,
+        base_delay: DEFAULT_BASE_DELAY,
+        request_hook: None,
         phantom: PhantomData,
     }
 }
 
+impl<T: Record> Base<T> {
+    /// Sets how many times a request that's hit Airtable's rate limit (HTTP
+    /// 429) is retried before the error is surfaced to the caller.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the base delay for the exponential backoff used between
+    /// rate-limit retries. Doubles on each attempt and is randomly jittered.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Registers a hook run on every outgoing request just before it's sent,
+    /// for cross-cutting concerns like logging, custom headers, auth
+    /// refresh, or throttling, without forking the crate.
+    pub fn with_request_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&mut reqwest::RequestBuilder) + Send + Sync + 'static,
+    {
+        self.request_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Points requests at a different API root instead of Airtable's, so
+    /// tests can run against a `mockito` server.
+    #[cfg(test)]
+    pub(crate) fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+}
+
+/// Sends the request built by `build_request`, retrying on HTTP 429 with
+/// exponential backoff and jitter (honoring `Retry-After` when Airtable sends
+/// one) until `max_retries` is exhausted.
+async fn send_with_retry<F>(
+    max_retries: u32,
+    base_delay: Duration,
+    request_hook: Option<Arc<RequestHook>>,
+    mut build_request: F,
+) -> Result<reqwest::Response, Error>
+where
+    F: FnMut() -> reqwest::RequestBuilder,
+{
+    let mut attempt = 0;
+
+    loop {
+        let mut request = build_request();
+        if let Some(ref hook) = request_hook {
+            hook(&mut request);
+        }
+
+        let response = request.send().await?;
+
+        if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS || attempt >= max_retries {
+            return response_or_error(response).await;
+        }
+
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs);
+
+        let delay = retry_after.unwrap_or_else(|| backoff_delay(base_delay, attempt));
+        let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=delay.as_millis() as u64));
+
+        tokio::time::sleep(delay + jitter).await;
+        attempt += 1;
+    }
+}
+
+/// The un-jittered exponential backoff delay for `attempt` (0-indexed).
+/// Doubles `base_delay` per attempt, clamped to `MAX_BACKOFF`. The exponent
+/// stops at `MAX_BACKOFF_ATTEMPTS` so a large `attempt` (from a caller-raised
+/// `max_retries`) can't overflow `2u32.pow`.
+fn backoff_delay(base_delay: Duration, attempt: u32) -> Duration {
+    let exponent = attempt.min(MAX_BACKOFF_ATTEMPTS);
+    (base_delay * 2u32.pow(exponent)).min(MAX_BACKOFF)
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct SRecord<T> {
     #[serde(default, skip_serializing)]
@@ -172,94 +391,185 @@ struct RecordPage<T> {
     offset: String,
 }
 
+// Airtable caps batch create/update/delete at 10 records per request.
+const BATCH_LIMIT: usize = 10;
+
+#[derive(Serialize, Debug)]
+struct BatchRecords<T> {
+    records: Vec<T>,
+}
+
+#[derive(Deserialize, Debug)]
+struct BatchResponse<T> {
+    records: Vec<SRecord<T>>,
+}
+
+/// Returned by `create_many`/`update_many` when a chunk fails partway through
+/// a batch. `partial` holds the records from chunks that completed
+/// successfully *before* `error` occurred -- those are already persisted in
+/// Airtable, so callers can tell which records round-tripped instead of
+/// losing track of them.
+#[derive(Debug)]
+pub struct BatchError<T> {
+    pub partial: Vec<T>,
+    pub error: Error,
+}
+
+impl<T> fmt::Display for BatchError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} of the batch's records were already processed before this failure)",
+            self.error,
+            self.partial.len()
+        )
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for BatchError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+fn into_record<T: Record>(record: SRecord<T>) -> T {
+    let mut record_t = record.fields;
+    record_t.set_id(record.id);
+    record_t
+}
+
+#[derive(Serialize)]
+struct PageParams<'a> {
+    offset: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    view: Option<&'a str>,
+    #[serde(rename = "filterByFormula", skip_serializing_if = "Option::is_none")]
+    formula: Option<&'a str>,
+    #[serde(rename = "pageSize", skip_serializing_if = "Option::is_none")]
+    page_size: Option<u16>,
+    #[serde(rename = "maxRecords", skip_serializing_if = "Option::is_none")]
+    max_records: Option<usize>,
+}
+
+fn page_url<T: Record>(
+    base: &Base<T>,
+    query_builder: &QueryBuilder<'_, T>,
+    offset: &str,
+) -> Url {
+    let mut url = Url::parse(&format!("{}/{}/{}", base.base_url, base.app_key, base.table)).unwrap();
+
+    let params = PageParams {
+        offset,
+        view: query_builder.view.as_deref(),
+        formula: query_builder.formula.as_deref(),
+        page_size: query_builder.page_size,
+        max_records: query_builder.max_records,
+    };
+
+    url.set_query(Some(
+        &serde_urlencoded::to_string(&params).expect("failed to encode query params"),
+    ));
+
+    // `serde_urlencoded` only flattens scalar fields; it can't serialize a
+    // `Vec<&str>` as repeated `fields[]=...` pairs, so those are appended
+    // by hand (same as `sort[n][...]` below).
+    if let Some(ref fields) = query_builder.fields {
+        let mut pairs = url.query_pairs_mut();
+        for field in fields {
+            pairs.append_pair("fields[]", field);
+        }
+    }
+
+    if let Some(ref sort) = query_builder.sort {
+        for (i, sort) in sort.iter().enumerate() {
+            url.query_pairs_mut()
+                .append_pair(&format!("sort[{}][field]", i), &sort.0);
+            url.query_pairs_mut()
+                .append_pair(&format!("sort[{}][direction]", i), &sort.1.to_string());
+        }
+    }
+
+    url
+}
+
+/// Lazily paginates through a query's results.
+///
+/// `Paginator` implements `futures::Stream` rather than blocking the thread
+/// per page: it yields buffered records from the current page, and once
+/// that's drained, fetches the next one (if `offset` says there is one) and
+/// refills the buffer.
 pub struct Paginator<'base, T: Record> {
     base: &'base Base<T>,
     // TODO: Move the offset to query_builder
     offset: Option<String>,
     iterator: std::vec::IntoIter<T>,
     query_builder: QueryBuilder<'base, T>,
+    in_flight: Option<BoxFuture<'static, Result<RecordPage<T>, Error>>>,
 }
 
-impl<'base, T> Iterator for Paginator<'base, T>
+impl<'base, T> Stream for Paginator<'base, T>
 where
     for<'de> T: Deserialize<'de>,
-    T: Record,
+    T: Record + Send + Unpin + 'static,
 {
-    type Item = T;
-    // This somewhat masks errors..
-    fn next(&mut self) -> Option<Self::Item> {
-        let next = self.iterator.next();
-        if next.is_some() {
-            return next;
-        }
+    type Item = Result<T, Error>;
 
-        if self.offset.is_none() {
-            return None;
-        }
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
 
-        let mut url = Url::parse(&format!(
-            "{}/{}/{}",
-            URL, self.base.app_key, self.base.table
-        ))
-        .unwrap();
-        url.query_pairs_mut()
-            .append_pair("offset", self.offset.as_ref().unwrap());
+        loop {
+            if let Some(record) = this.iterator.next() {
+                return Poll::Ready(Some(Ok(record)));
+            }
 
-        if self.query_builder.view.is_some() {
-            url.query_pairs_mut()
-                .append_pair("view", self.query_builder.view.as_ref().unwrap());
-        }
+            if this.in_flight.is_none() {
+                let offset = match this.offset {
+                    Some(ref offset) => offset.clone(),
+                    None => return Poll::Ready(None),
+                };
 
-        if self.query_builder.formula.is_some() {
-            url.query_pairs_mut().append_pair(
-                "filterByFormula",
-                self.query_builder.formula.as_ref().unwrap(),
-            );
-        }
+                let url = page_url(this.base, &this.query_builder, &offset);
+                let http_client = this.base.http_client.clone();
+                let max_retries = this.base.max_retries;
+                let base_delay = this.base.base_delay;
+                let request_hook = this.base.request_hook.clone();
 
-        if self.query_builder.sort.is_some() {
-            for (i, ref sort) in self.query_builder.sort.as_ref().unwrap().iter().enumerate() {
-                url.query_pairs_mut()
-                    .append_pair(&format!("sort[{}][field]", i), &sort.0);
-                url.query_pairs_mut()
-                    .append_pair(&format!("sort[{}][direction]", i), &sort.1.to_string());
+                this.in_flight = Some(Box::pin(async move {
+                    let response = send_with_retry(max_retries, base_delay, request_hook, || {
+                        http_client.get(url.as_str())
+                    })
+                    .await?;
+                    let page: RecordPage<T> = read_json(response).await?;
+                    Ok(page)
+                }));
             }
-        }
-
-        // println!("{}", url);
 
-        let mut response = self
-            .base
-            .http_client
-            .get(url.as_str())
-            .send()
-            .ok()?;
+            let page = match this.in_flight.as_mut().unwrap().as_mut().poll(cx) {
+                Poll::Ready(page) => page,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.in_flight = None;
 
-        let results: RecordPage<T> = response.json().ok()?;
+            let page = match page {
+                Ok(page) => page,
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            };
 
-        if results.offset.is_empty() {
-            self.offset = None;
-        } else {
-            self.offset = Some(results.offset);
-        }
+            this.offset = if page.offset.is_empty() {
+                None
+            } else {
+                Some(page.offset)
+            };
 
-        let window: Vec<T> = results
-            .records
-            .into_iter()
-            .map(|record| {
-                let mut record_t: T = record.fields;
-                record_t.set_id(record.id);
-                record_t
-            })
-            .collect();
+            let window: Vec<T> = page.records.into_iter().map(into_record).collect();
 
-        self.iterator = window.into_iter();
-        self.iterator.next()
+            this.iterator = window.into_iter();
+        }
     }
 }
 
 pub trait Record {
-    fn set_id(&mut self, String);
+    fn set_id(&mut self, id: String);
     fn id(&self) -> &str;
 }
 
@@ -268,11 +578,11 @@ pub enum SortDirection {
     Ascending,
 }
 
-impl ToString for SortDirection {
-    fn to_string(&self) -> String {
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            SortDirection::Descending => String::from("desc"),
-            SortDirection::Ascending => String::from("asc"),
+            SortDirection::Descending => write!(f, "desc"),
+            SortDirection::Ascending => write!(f, "asc"),
         }
     }
 }
@@ -283,6 +593,8 @@ pub struct QueryBuilder<'base, T: Record> {
     fields: Option<Vec<String>>,
     view: Option<String>,
     formula: Option<String>,
+    page_size: Option<u16>,
+    max_records: Option<usize>,
 
     // TODO: Second value here should be an enum.
     sort: Option<Vec<(String, SortDirection)>>,
@@ -315,22 +627,34 @@ where
         };
         self
     }
-}
 
-impl<'base, T> IntoIterator for QueryBuilder<'base, T>
-where
-    for<'de> T: Deserialize<'de>,
-    T: Record,
-{
-    type Item = T;
-    type IntoIter = Paginator<'base, T>;
+    /// Limits how many records Airtable returns per page (`pageSize`).
+    pub fn page_size(mut self, page_size: u16) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Caps the total number of records returned across all pages (`maxRecords`).
+    pub fn max_records(mut self, max_records: usize) -> Self {
+        self.max_records = Some(max_records);
+        self
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
+    /// Projects the response down to only the named fields, which materially
+    /// reduces payload size when a struct only maps a handful of columns.
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.fields = Some(fields.iter().map(|field| field.to_string()).collect());
+        self
+    }
+
+    /// Turns the query into a lazily-paginating `Stream` of records.
+    pub fn into_stream(self) -> Paginator<'base, T> {
         Paginator {
-            base: &self.base,
+            base: self.base,
             offset: Some("".to_owned()),
             iterator: vec![].into_iter(),
             query_builder: self,
+            in_flight: None,
         }
     }
 }
@@ -340,21 +664,23 @@ where
     for<'de> T: Deserialize<'de>,
     T: Record,
 {
-    pub fn query(&self) -> QueryBuilder<T> {
+    pub fn query(&self) -> QueryBuilder<'_, T> {
         QueryBuilder {
             base: self,
             fields: None,
             view: None,
             formula: None,
+            page_size: None,
+            max_records: None,
             sort: None,
         }
     }
 
-    pub fn create(&self, record: &T) -> Result<(), Error>
+    pub async fn create(&self, record: &T) -> Result<(), Error>
     where
         T: serde::Serialize,
     {
-        let url = format!("{}/{}/{}", URL, self.app_key, self.table);
+        let url = format!("{}/{}/{}", self.base_url, self.app_key, self.table);
 
         let serializing_record = SRecord {
             id: String::new(),
@@ -363,24 +689,24 @@ where
 
         let json = serde_json::to_string(&serializing_record)?;
 
-        self.http_client
-            .post(&url)
-            .body(json)
-            .send()?
-            .error_for_status()?;
+        send_with_retry(
+            self.max_retries,
+            self.base_delay,
+            self.request_hook.clone(),
+            || self.http_client.post(&url).body(json.clone()),
+        )
+        .await?;
 
         Ok(())
     }
 
     // TODO: Perhaps pass a mutable reference to allow updating computed fields when someone does
     // an update?
-    //
-    // TODO: Include the error body in the error.
-    pub fn update(&self, record: &T) -> Result<(), Error>
+    pub async fn update(&self, record: &T) -> Result<(), Error>
     where
         T: serde::Serialize,
     {
-        let url = format!("{}/{}/{}/{}", URL, self.app_key, self.table, record.id());
+        let url = format!("{}/{}/{}/{}", self.base_url, self.app_key, self.table, record.id());
 
         let serializing_record = SRecord {
             id: record.id().to_owned(),
@@ -389,12 +715,417 @@ where
 
         let json = serde_json::to_string(&serializing_record)?;
 
-        self.http_client
-            .patch(&url)
-            .body(json)
-            .send()?
-            .error_for_status()?;
+        send_with_retry(
+            self.max_retries,
+            self.base_delay,
+            self.request_hook.clone(),
+            || self.http_client.patch(&url).body(json.clone()),
+        )
+        .await?;
 
         Ok(())
     }
+
+    /// Fetches a single record by id.
+    pub async fn find(&self, id: &str) -> Result<T, Error> {
+        let url = format!("{}/{}/{}/{}", self.base_url, self.app_key, self.table, id);
+
+        let response = send_with_retry(
+            self.max_retries,
+            self.base_delay,
+            self.request_hook.clone(),
+            || self.http_client.get(&url),
+        )
+        .await?;
+
+        let record: SRecord<T> = read_json(response).await?;
+        Ok(into_record(record))
+    }
+
+    /// Deletes the record with the given id.
+    pub async fn delete(&self, id: &str) -> Result<(), Error> {
+        let url = format!("{}/{}/{}/{}", self.base_url, self.app_key, self.table, id);
+
+        send_with_retry(
+            self.max_retries,
+            self.base_delay,
+            self.request_hook.clone(),
+            || self.http_client.delete(&url),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes `record`, looking up its id via `Record::id`.
+    pub async fn destroy(&self, record: &T) -> Result<(), Error> {
+        self.delete(record.id()).await
+    }
+
+    /// Creates up to 10 records per request, chunking `records` as needed, and
+    /// returns them with the ids Airtable assigned.
+    ///
+    /// If a chunk fails, the records from chunks that already succeeded --
+    /// and are therefore already persisted in Airtable -- are returned
+    /// alongside the error via `BatchError::partial`, rather than discarded.
+    pub async fn create_many(&self, records: &[T]) -> Result<Vec<T>, BatchError<T>>
+    where
+        T: serde::Serialize,
+    {
+        let url = format!("{}/{}/{}", self.base_url, self.app_key, self.table);
+        let mut created = Vec::with_capacity(records.len());
+
+        for chunk in records.chunks(BATCH_LIMIT) {
+            let body = BatchRecords {
+                records: chunk
+                    .iter()
+                    .map(|record| SRecord {
+                        id: String::new(),
+                        fields: record,
+                    })
+                    .collect(),
+            };
+
+            let json = match serde_json::to_string(&body) {
+                Ok(json) => json,
+                Err(err) => {
+                    return Err(BatchError {
+                        partial: created,
+                        error: err.into(),
+                    })
+                }
+            };
+
+            let response = match send_with_retry(
+                self.max_retries,
+                self.base_delay,
+                self.request_hook.clone(),
+                || self.http_client.post(&url).body(json.clone()),
+            )
+            .await
+            {
+                Ok(response) => response,
+                Err(error) => return Err(BatchError { partial: created, error }),
+            };
+
+            let page: BatchResponse<T> = match read_json(response).await {
+                Ok(page) => page,
+                Err(error) => return Err(BatchError { partial: created, error }),
+            };
+
+            created.extend(page.records.into_iter().map(into_record));
+        }
+
+        Ok(created)
+    }
+
+    /// Updates up to 10 records per request, chunking `records` as needed, and
+    /// returns the updated records.
+    ///
+    /// If a chunk fails, the records from chunks that already succeeded --
+    /// and are therefore already updated in Airtable -- are returned
+    /// alongside the error via `BatchError::partial`, rather than discarded.
+    pub async fn update_many(&self, records: &[T]) -> Result<Vec<T>, BatchError<T>>
+    where
+        T: serde::Serialize,
+    {
+        let url = format!("{}/{}/{}", self.base_url, self.app_key, self.table);
+        let mut updated = Vec::with_capacity(records.len());
+
+        for chunk in records.chunks(BATCH_LIMIT) {
+            let body = BatchRecords {
+                records: chunk
+                    .iter()
+                    .map(|record| SRecord {
+                        id: record.id().to_owned(),
+                        fields: record,
+                    })
+                    .collect(),
+            };
+
+            let json = match serde_json::to_string(&body) {
+                Ok(json) => json,
+                Err(err) => {
+                    return Err(BatchError {
+                        partial: updated,
+                        error: err.into(),
+                    })
+                }
+            };
+
+            let response = match send_with_retry(
+                self.max_retries,
+                self.base_delay,
+                self.request_hook.clone(),
+                || self.http_client.patch(&url).body(json.clone()),
+            )
+            .await
+            {
+                Ok(response) => response,
+                Err(error) => return Err(BatchError { partial: updated, error }),
+            };
+
+            let page: BatchResponse<T> = match read_json(response).await {
+                Ok(page) => page,
+                Err(error) => return Err(BatchError { partial: updated, error }),
+            };
+
+            updated.extend(page.records.into_iter().map(into_record));
+        }
+
+        Ok(updated)
+    }
+
+    /// Deletes up to 10 records per request, chunking `ids` as needed.
+    pub async fn delete_many(&self, ids: &[&str]) -> Result<(), Error> {
+        let base_url = format!("{}/{}/{}", self.base_url, self.app_key, self.table);
+
+        for chunk in ids.chunks(BATCH_LIMIT) {
+            let mut url = Url::parse(&base_url).unwrap();
+            {
+                let mut pairs = url.query_pairs_mut();
+                for id in chunk {
+                    pairs.append_pair("records[]", id);
+                }
+            }
+
+            send_with_retry(
+                self.max_retries,
+                self.base_delay,
+                self.request_hook.clone(),
+                || self.http_client.delete(url.as_str()),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_doubles_each_attempt() {
+        let base = Duration::from_millis(200);
+        assert_eq!(backoff_delay(base, 0), base);
+        assert_eq!(backoff_delay(base, 1), base * 2);
+        assert_eq!(backoff_delay(base, 2), base * 4);
+    }
+
+    #[test]
+    fn backoff_delay_is_capped_and_does_not_overflow() {
+        let base = Duration::from_millis(200);
+
+        // Without a cap, `2u32.pow(32)` panics (debug) / wraps (release).
+        // With `max_retries` raised well past that, the delay should just
+        // saturate at `MAX_BACKOFF` instead.
+        assert_eq!(backoff_delay(base, 32), MAX_BACKOFF);
+        assert_eq!(backoff_delay(base, u32::MAX), MAX_BACKOFF);
+        assert_eq!(backoff_delay(base, MAX_BACKOFF_ATTEMPTS), MAX_BACKOFF);
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Dummy {
+        #[serde(default, skip_serializing)]
+        id: String,
+        value: i64,
+    }
+
+    impl Record for Dummy {
+        fn set_id(&mut self, id: String) {
+            self.id = id;
+        }
+
+        fn id(&self) -> &str {
+            &self.id
+        }
+    }
+
+    fn dummies(count: i64) -> Vec<Dummy> {
+        (0..count)
+            .map(|value| Dummy {
+                id: String::new(),
+                value,
+            })
+            .collect()
+    }
+
+    // `mockito`'s 0.31 API runs a single server per process, so tests that
+    // use it must not run concurrently with each other.
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn create_many_splits_into_chunks_of_batch_limit() {
+        let mock = mockito::mock("POST", "/appKey/Dummies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"records":[]}"#)
+            // 23 records -> chunks of 10, 10, 3: three requests.
+            .expect(3)
+            .create();
+
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url(&mockito::server_url());
+        let created = base.create_many(&dummies(23)).await.unwrap();
+
+        assert!(created.is_empty());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn update_many_splits_into_chunks_of_batch_limit() {
+        let mock = mockito::mock("PATCH", "/appKey/Dummies")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"records":[]}"#)
+            // 20 records -> chunks of 10, 10: two requests.
+            .expect(2)
+            .create();
+
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url(&mockito::server_url());
+        let updated = base.update_many(&dummies(20)).await.unwrap();
+
+        assert!(updated.is_empty());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn create_many_with_no_records_sends_no_requests() {
+        // Any unmocked request to this base would fail to connect, so a
+        // successful, empty result here proves the loop never ran.
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url("http://127.0.0.1:0");
+        let created = base.create_many(&[]).await.unwrap();
+
+        assert!(created.is_empty());
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn delete_many_splits_into_chunks_of_batch_limit() {
+        let mock = mockito::mock("DELETE", mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{}")
+            // 23 ids -> chunks of 10, 10, 3: three requests.
+            .expect(3)
+            .create();
+
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url(&mockito::server_url());
+        let ids: Vec<&str> = (0..23).map(|_| "rec").collect();
+        base.delete_many(&ids).await.unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn delete_many_with_no_ids_sends_no_requests() {
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url("http://127.0.0.1:0");
+        base.delete_many(&[]).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn create_many_returns_partial_results_alongside_the_error() {
+        // The first chunk (values 0..9) succeeds...
+        let ok_mock = mockito::mock("POST", "/appKey/Dummies")
+            .match_body(mockito::Matcher::Regex(r#""value":0"#.to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"records":[{"id":"rec-a","fields":{"value":1}},{"id":"rec-b","fields":{"value":2}}]}"#)
+            .create();
+
+        // ...but the second chunk (value 10 only) is rejected.
+        let err_mock = mockito::mock("POST", "/appKey/Dummies")
+            .match_body(mockito::Matcher::Regex(r#""value":10"#.to_string()))
+            .with_status(500)
+            .create();
+
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url(&mockito::server_url());
+        let err = base.create_many(&dummies(11)).await.unwrap_err();
+
+        assert_eq!(err.partial.len(), 2);
+        assert!(matches!(err.error, Error::Http(_, _)));
+
+        ok_mock.assert();
+        err_mock.assert();
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn query_sends_page_size_max_records_and_repeated_fields_pairs() {
+        use futures::StreamExt;
+
+        // `fields[]` is percent-encoded by `Url`, and is matched here as a
+        // regex (rather than `Matcher::UrlEncoded`) because that matcher
+        // only keeps the last value of a repeated query key.
+        let mock = mockito::mock("GET", "/appKey/Dummies")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("pageSize".into(), "5".into()),
+                mockito::Matcher::UrlEncoded("maxRecords".into(), "50".into()),
+                mockito::Matcher::Regex("fields%5B%5D=Word".into()),
+                mockito::Matcher::Regex("fields%5B%5D=Google".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"records":[],"offset":""}"#)
+            .create();
+
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url(&mockito::server_url());
+        let mut stream = base
+            .query()
+            .page_size(5)
+            .max_records(50)
+            .fields(&["Word", "Google"])
+            .into_stream();
+
+        assert!(stream.next().await.is_none());
+        mock.assert();
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn error_http_captures_the_response_body_on_failure() {
+        let mock = mockito::mock("GET", "/appKey/Dummies/rec1")
+            .with_status(422)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":{"type":"INVALID_VALUE_FOR_COLUMN","message":"nope"}}"#)
+            .create();
+
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies").base_url(&mockito::server_url());
+        let err = base.find("rec1").await.unwrap_err();
+
+        match err {
+            Error::Http(_, Some(body)) => {
+                assert_eq!(body["error"]["type"], "INVALID_VALUE_FOR_COLUMN");
+            }
+            other => panic!("expected Error::Http with a captured body, got {:?}", other),
+        }
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn request_hook_is_invoked_on_outgoing_requests() {
+        let mock = mockito::mock("GET", "/appKey/Dummies/rec1")
+            .match_header("x-test-hook", "called")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"rec1","fields":{"value":1}}"#)
+            .create();
+
+        let base = crate::new::<Dummy>("key", "appKey", "Dummies")
+            .base_url(&mockito::server_url())
+            .with_request_hook(|req| {
+                *req = req
+                    .try_clone()
+                    .expect("request has no streaming body yet, so it's always clonable")
+                    .header("x-test-hook", "called");
+            });
+
+        base.find("rec1").await.unwrap();
+
+        mock.assert();
+    }
 }